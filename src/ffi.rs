@@ -0,0 +1,236 @@
+//! C-ABI surface for non-Rust callers (mobile, C++, on-chain glue). Every function takes
+//! opaque handle pointers and raw `*const`/`*mut u8` buffers (encoded with the wire format
+//! in `crate::serialize`) and reports failures via `EcAccStatus` rather than panicking or
+//! unwinding across the FFI boundary.
+
+use crate::serialize::{self, DecodeError, FR_BYTES, G1_BYTES};
+use crate::Bn254Accumulator;
+use std::slice;
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcAccStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidLength = 2,
+    InvalidPoint = 3,
+    NotFound = 4,
+    CapacityExceeded = 5,
+}
+
+impl From<DecodeError> for EcAccStatus {
+    fn from(err: DecodeError) -> Self {
+        match err {
+            DecodeError::UnexpectedLength => EcAccStatus::InvalidLength,
+            DecodeError::InvalidPoint => EcAccStatus::InvalidPoint,
+        }
+    }
+}
+
+/// Opaque handle owning a `Bn254Accumulator`. Never constructed or read from outside this
+/// module; callers only ever hold a pointer to one.
+pub struct EcAccHandle(Bn254Accumulator);
+
+/// Creates a new accumulator (see `Bn254Accumulator::setup`) sized for up to `degree`
+/// members, returning an owned handle. The caller must release it with `ec_acc_free`.
+#[no_mangle]
+pub extern "C" fn ec_acc_new(degree: usize) -> *mut EcAccHandle {
+    Box::into_raw(Box::new(EcAccHandle(Bn254Accumulator::setup(degree))))
+}
+
+/// Frees a handle created by `ec_acc_new` or `ec_acc_deserialize`. A null pointer is a
+/// no-op.
+#[no_mangle]
+pub extern "C" fn ec_acc_free(handle: *mut EcAccHandle) {
+    if handle.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Adds a member and writes its `FR_BYTES`-byte big-endian scalar into `out_scalar`.
+/// Returns `CapacityExceeded` if the accumulator is already at its SRS-bounded capacity,
+/// leaving `out_scalar` untouched.
+#[no_mangle]
+pub extern "C" fn ec_acc_add_member(
+    handle: *mut EcAccHandle,
+    member: *const u8,
+    member_len: usize,
+    out_scalar: *mut u8,
+) -> EcAccStatus {
+    if handle.is_null() || member.is_null() || out_scalar.is_null() {
+        return EcAccStatus::NullPointer;
+    }
+
+    let acc = unsafe { &mut (*handle).0 };
+    let member_bytes = unsafe { slice::from_raw_parts(member, member_len) };
+    let x = match acc.add_member(member_bytes) {
+        Some(x) => x,
+        None => return EcAccStatus::CapacityExceeded,
+    };
+
+    let out = unsafe { slice::from_raw_parts_mut(out_scalar, FR_BYTES) };
+    out.copy_from_slice(&serialize::fr_to_be_bytes(x));
+    EcAccStatus::Ok
+}
+
+/// Writes the `G1_BYTES`-byte big-endian membership witness for `member` (a
+/// `FR_BYTES`-byte big-endian scalar) into `out_witness`. Returns `NotFound` if `member`
+/// is not currently accumulated.
+#[no_mangle]
+pub extern "C" fn ec_acc_membership_witness(
+    handle: *const EcAccHandle,
+    member: *const u8,
+    out_witness: *mut u8,
+) -> EcAccStatus {
+    if handle.is_null() || member.is_null() || out_witness.is_null() {
+        return EcAccStatus::NullPointer;
+    }
+
+    let acc = unsafe { &(*handle).0 };
+    let member_bytes = unsafe { slice::from_raw_parts(member, FR_BYTES) };
+    let x = match serialize::fr_from_be_bytes(member_bytes) {
+        Ok(x) => x,
+        Err(err) => return err.into(),
+    };
+
+    match acc.membership_witness(x) {
+        Some(w) => {
+            let out = unsafe { slice::from_raw_parts_mut(out_witness, G1_BYTES) };
+            out.copy_from_slice(&serialize::g1_to_be_bytes(w));
+            EcAccStatus::Ok
+        }
+        None => EcAccStatus::NotFound,
+    }
+}
+
+/// Verifies a membership witness. `member` is `FR_BYTES` bytes, `witness` is `G1_BYTES`
+/// bytes, both big-endian. Returns `Ok` if the witness verifies, `NotFound` if it does
+/// not (the member is not currently accumulated, or the witness is stale/forged).
+#[no_mangle]
+pub extern "C" fn ec_acc_verify_membership(
+    handle: *const EcAccHandle,
+    member: *const u8,
+    witness: *const u8,
+) -> EcAccStatus {
+    if handle.is_null() || member.is_null() || witness.is_null() {
+        return EcAccStatus::NullPointer;
+    }
+
+    let acc = unsafe { &(*handle).0 };
+    let member_bytes = unsafe { slice::from_raw_parts(member, FR_BYTES) };
+    let witness_bytes = unsafe { slice::from_raw_parts(witness, G1_BYTES) };
+
+    let x = match serialize::fr_from_be_bytes(member_bytes) {
+        Ok(x) => x,
+        Err(err) => return err.into(),
+    };
+    let w = match serialize::g1_from_be_bytes(witness_bytes) {
+        Ok(w) => w,
+        Err(err) => return err.into(),
+    };
+
+    if acc.verify_membership(x, w) {
+        EcAccStatus::Ok
+    } else {
+        EcAccStatus::NotFound
+    }
+}
+
+/// Serializes the accumulator's full state into a freshly allocated buffer (see
+/// `Bn254Accumulator::to_bytes`). The caller takes ownership of `*out_buf` and must
+/// release it via `ec_acc_free_buffer`, passing back the `*out_len` written here.
+#[no_mangle]
+pub extern "C" fn ec_acc_serialize(
+    handle: *const EcAccHandle,
+    out_buf: *mut *mut u8,
+    out_len: *mut usize,
+) -> EcAccStatus {
+    if handle.is_null() || out_buf.is_null() || out_len.is_null() {
+        return EcAccStatus::NullPointer;
+    }
+
+    let acc = unsafe { &(*handle).0 };
+    let mut bytes = acc.to_bytes().into_boxed_slice();
+    unsafe {
+        *out_len = bytes.len();
+        *out_buf = bytes.as_mut_ptr();
+    }
+    std::mem::forget(bytes);
+    EcAccStatus::Ok
+}
+
+/// Deserializes an accumulator previously produced by `ec_acc_serialize`, returning an
+/// owned handle the caller must release with `ec_acc_free`. Returns a null pointer if
+/// `buf` does not decode to a valid accumulator.
+#[no_mangle]
+pub extern "C" fn ec_acc_deserialize(buf: *const u8, len: usize) -> *mut EcAccHandle {
+    if buf.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let bytes = unsafe { slice::from_raw_parts(buf, len) };
+    match Bn254Accumulator::from_bytes(bytes) {
+        Ok(acc) => Box::into_raw(Box::new(EcAccHandle(acc))),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Frees a buffer allocated by `ec_acc_serialize`. `len` must be the value written to
+/// `out_len` by that call.
+#[no_mangle]
+pub extern "C" fn ec_acc_free_buffer(buf: *mut u8, len: usize) {
+    if buf.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Vec::from_raw_parts(buf, len, len));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ffi_round_trip() {
+        unsafe {
+            let handle = ec_acc_new(8);
+            assert!(!handle.is_null());
+
+            let member = b"alice";
+            let mut scalar = [0u8; FR_BYTES];
+            assert_eq!(
+                ec_acc_add_member(handle, member.as_ptr(), member.len(), scalar.as_mut_ptr()),
+                EcAccStatus::Ok
+            );
+
+            let mut witness = [0u8; G1_BYTES];
+            assert_eq!(
+                ec_acc_membership_witness(handle, scalar.as_ptr(), witness.as_mut_ptr()),
+                EcAccStatus::Ok
+            );
+            assert_eq!(
+                ec_acc_verify_membership(handle, scalar.as_ptr(), witness.as_ptr()),
+                EcAccStatus::Ok
+            );
+
+            let mut buf: *mut u8 = std::ptr::null_mut();
+            let mut len: usize = 0;
+            assert_eq!(ec_acc_serialize(handle, &mut buf, &mut len), EcAccStatus::Ok);
+
+            let restored = ec_acc_deserialize(buf, len);
+            assert!(!restored.is_null());
+            assert_eq!(
+                ec_acc_verify_membership(restored, scalar.as_ptr(), witness.as_ptr()),
+                EcAccStatus::Ok
+            );
+
+            ec_acc_free_buffer(buf, len);
+            ec_acc_free(handle);
+            ec_acc_free(restored);
+        }
+    }
+}
@@ -0,0 +1,547 @@
+//! Zero-knowledge membership proofs over [`Bn254Accumulator`], plus an exportable
+//! Solidity verifier so a proof can be checked on-chain.
+//!
+//! A literal in-circuit encoding of the accumulator's pairing check
+//! `e(w, g2^s * g2^x) == e(acc, g2)` would require emulating BN254's own extension-field
+//! pairing arithmetic inside an R1CS circuit natively defined over BN254's scalar field -
+//! the usual reason pairing-accumulator systems built on top of a single curve don't SNARK
+//! the pairing check itself. Instead, following the same tradeoff real deployments of
+//! pairing accumulators make (e.g. Semaphore/RLN-style systems, see the `rln` module),
+//! this module SNARKs a fixed-depth Merkle-tree membership argument over `acc.members`
+//! instead: the leaves are the accumulator's actual member scalars (padded with zero up to
+//! `2^MERKLE_DEPTH`), internal nodes are `mimc_hash(left, right)`, and the circuit proves
+//! knowledge of a leaf `x` and a root-to-leaf path that recomputes the public `root` - the
+//! same root anyone can recompute directly from `acc.members` via `merkle_root`. Unlike a
+//! self-chosen commitment, this root is *not* free for a prover to pick: it is fixed by the
+//! accumulator's actual member set, so a path only exists for `x` values that are really
+//! accumulated. The member value itself is never revealed.
+
+use crate::Bn254Accumulator;
+use ark_bn254::{Bn254, Fr};
+use ark_ec::CurveGroup;
+use ark_ff::Field;
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_r1cs_std::{
+    alloc::AllocVar, boolean::Boolean, eq::EqGadget, fields::fp::FpVar, select::CondSelectGadget,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snark::SNARK;
+use ark_std::rand::{CryptoRng, RngCore};
+
+/// Number of MiMC rounds used for the in-circuit hash. MiMC is used here (rather than the
+/// crate's Keccak-based `hash_to_scalar`) because it is cheap to express as R1CS
+/// constraints; Keccak is not.
+const MIMC_ROUNDS: usize = 22;
+
+/// Depth of the membership Merkle tree, i.e. `2^MERKLE_DEPTH` leaves. Member sets smaller
+/// than this are padded with zero leaves (`Fr::ZERO`); since `hash_to_scalar` and
+/// `add_member_scalar` inputs are never actually zero in practice, a zero leaf can never be
+/// mistaken for a real member.
+pub(crate) const MERKLE_DEPTH: usize = 8;
+
+fn mimc_round_constants() -> Vec<Fr> {
+    (0..MIMC_ROUNDS)
+        .map(|i| Bn254Accumulator::hash_to_scalar(format!("ec-accumulator-mimc-round-{i}").as_bytes()))
+        .collect()
+}
+
+/// Description: The native (out-of-circuit) MiMC compression function, used both as the
+/// Merkle tree's 2-to-1 hash (`mimc_hash(left, right)`) and, identically, inside
+/// `MembershipCircuit` and `rln::RlnCircuit` so in-circuit and out-of-circuit computations
+/// always agree.
+/// Method: mimc_hash
+/// Parameters: x - the first input, k - the second input
+/// Response: The MiMC output, a single Fr scalar
+pub fn mimc_hash(x: Fr, k: Fr) -> Fr {
+    let mut state = x;
+    for c in mimc_round_constants() {
+        let t = state + k + c;
+        state = t * t * t;
+    }
+    state + k
+}
+
+pub(crate) fn mimc_gadget(x: &FpVar<Fr>, k: &FpVar<Fr>) -> Result<FpVar<Fr>, SynthesisError> {
+    let mut state = x.clone();
+    for c in mimc_round_constants() {
+        let t = &state + k + FpVar::constant(c);
+        let squared = t.square()?;
+        state = &squared * &t;
+    }
+    Ok(state + k)
+}
+
+/// Description: The in-circuit analogue of `merkle_root`/`merkle_path`: recomputes the
+/// Merkle root for `leaf` by walking the supplied authentication path, allocating each
+/// path step as a witness. Shared by `MembershipCircuit` and `rln::RlnCircuit`, which both
+/// need to bind a leaf to an accumulator's member-set root without revealing which leaf.
+/// Method: merkle_root_gadget
+/// Parameters: cs - the constraint system to allocate into, leaf - the leaf node,
+///             path - the authentication path (as native `(sibling, is_right)` pairs,
+///             `None` during setup when no concrete assignment exists yet)
+/// Response: The FpVar holding the recomputed root
+pub(crate) fn merkle_root_gadget(
+    cs: ConstraintSystemRef<Fr>,
+    leaf: &FpVar<Fr>,
+    path: &Option<Vec<(Fr, bool)>>,
+) -> Result<FpVar<Fr>, SynthesisError> {
+    let mut node = leaf.clone();
+    for i in 0..MERKLE_DEPTH {
+        let step = path.as_ref().map(|p| p[i]);
+        let sibling = FpVar::new_witness(cs.clone(), || {
+            step.map(|(s, _)| s).ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let is_right = Boolean::new_witness(cs.clone(), || {
+            step.map(|(_, r)| r).ok_or(SynthesisError::AssignmentMissing)
+        })?;
+
+        let left = FpVar::conditionally_select(&is_right, &sibling, &node)?;
+        let right = FpVar::conditionally_select(&is_right, &node, &sibling)?;
+        node = mimc_gadget(&left, &right)?;
+    }
+    Ok(node)
+}
+
+fn merkle_leaves(members: &[Fr]) -> Vec<Fr> {
+    let mut leaves = members.to_vec();
+    leaves.resize(1 << MERKLE_DEPTH, Fr::ZERO);
+    leaves
+}
+
+/// Description: Computes the root of the fixed-depth Merkle tree over `members` (the
+/// accumulator's actual member list, zero-padded to `2^MERKLE_DEPTH` leaves), using
+/// `mimc_hash` as the 2-to-1 compression function. Anyone can recompute this directly from
+/// a `Bn254Accumulator`'s public `members` field; a `Groth16MembershipProof`'s `root` must
+/// match it exactly.
+/// Method: merkle_root
+/// Parameters: members - the accumulator's member scalars
+/// Response: Some(root), or None if `members` has more than `2^MERKLE_DEPTH` entries (the
+///           tree's fixed leaf capacity) - `merkle_leaves` would otherwise silently
+///           truncate instead of representing every member
+pub(crate) fn merkle_root(members: &[Fr]) -> Option<Fr> {
+    if members.len() > 1 << MERKLE_DEPTH {
+        return None;
+    }
+    let mut level = merkle_leaves(members);
+    while level.len() > 1 {
+        level = level.chunks(2).map(|pair| mimc_hash(pair[0], pair[1])).collect();
+    }
+    Some(level[0])
+}
+
+/// Description: Builds the root-to-leaf authentication path for `x` within the Merkle tree
+/// over `members`, as `(sibling, x_is_right_child)` pairs from the leaf level up to the
+/// root.
+/// Method: merkle_path
+/// Parameters: members - the accumulator's member scalars, x - the member to build a path
+///             for
+/// Response: Some(path) if `x` is present in `members` and `members` is within the tree's
+///           `2^MERKLE_DEPTH` leaf capacity, None otherwise
+pub(crate) fn merkle_path(members: &[Fr], x: Fr) -> Option<Vec<(Fr, bool)>> {
+    if members.len() > 1 << MERKLE_DEPTH {
+        return None;
+    }
+    let mut idx = members.iter().position(|&m| m == x)?;
+    let mut level = merkle_leaves(members);
+    let mut path = Vec::with_capacity(MERKLE_DEPTH);
+    while level.len() > 1 {
+        let is_right = idx % 2 == 1;
+        path.push((level[idx ^ 1], is_right));
+        level = level.chunks(2).map(|pair| mimc_hash(pair[0], pair[1])).collect();
+        idx /= 2;
+    }
+    Some(path)
+}
+
+/// Description: Proves knowledge of a member `x` and a Merkle path from `x` up to the
+/// public `root`, without revealing `x` or the path. `root` is a public input; `x` and the
+/// path are private witnesses.
+struct MembershipCircuit {
+    x: Option<Fr>,
+    path: Option<Vec<(Fr, bool)>>,
+    root: Option<Fr>,
+}
+
+impl ConstraintSynthesizer<Fr> for MembershipCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let root = FpVar::new_input(cs.clone(), || self.root.ok_or(SynthesisError::AssignmentMissing))?;
+        let x = FpVar::new_witness(cs.clone(), || self.x.ok_or(SynthesisError::AssignmentMissing))?;
+        // Zero is the padding leaf, never a member.
+        x.enforce_not_equal(&FpVar::Constant(Fr::ZERO))?;
+
+        let computed_root = merkle_root_gadget(cs.clone(), &x, &self.path)?;
+        computed_root.enforce_equal(&root)?;
+        Ok(())
+    }
+}
+
+/// A zero-knowledge membership proof: a Groth16 proof plus the public Merkle root it was
+/// generated against.
+pub struct Groth16MembershipProof {
+    pub proof: Proof<Bn254>,
+    pub root: Fr,
+}
+
+impl Groth16MembershipProof {
+    /// Description: Serializes the proof (`a`, `b`, `c`) plus its public root to the
+    /// crate's fixed-width big-endian wire format.
+    /// Method: to_bytes
+    /// Parameters: none
+    /// Response: The serialized proof
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&crate::serialize::g1_to_be_bytes(self.proof.a.into()));
+        out.extend_from_slice(&crate::serialize::g2_to_be_bytes(self.proof.b.into()));
+        out.extend_from_slice(&crate::serialize::g1_to_be_bytes(self.proof.c.into()));
+        out.extend_from_slice(&crate::serialize::fr_to_be_bytes(self.root));
+        out
+    }
+
+    /// Description: Restores a proof previously serialized with `to_bytes`.
+    /// Method: from_bytes
+    /// Parameters: bytes - the serialized proof
+    /// Response: The restored proof, or a DecodeError if `bytes` is malformed
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, crate::serialize::DecodeError> {
+        let mut r = crate::serialize::Reader::new(bytes);
+        let a = crate::serialize::g1_from_be_bytes(r.take(crate::serialize::G1_BYTES)?)?.into_affine();
+        let b = crate::serialize::g2_from_be_bytes(r.take(crate::serialize::G2_BYTES)?)?.into_affine();
+        let c = crate::serialize::g1_from_be_bytes(r.take(crate::serialize::G1_BYTES)?)?.into_affine();
+        let root = crate::serialize::fr_from_be_bytes(r.take(crate::serialize::FR_BYTES)?)?;
+
+        Ok(Self {
+            proof: Proof { a, b, c },
+            root,
+        })
+    }
+}
+
+/// Description: Runs the (circuit-specific) Groth16 trusted setup for
+/// `MembershipCircuit`, producing a proving/verifying key pair.
+/// Method: setup_membership_circuit
+/// Parameters: rng - randomness source for the setup
+/// Response: (proving key, verifying key)
+pub fn setup_membership_circuit<R: RngCore + CryptoRng>(
+    rng: &mut R,
+) -> (ProvingKey<Bn254>, VerifyingKey<Bn254>) {
+    let circuit = MembershipCircuit {
+        x: None,
+        path: None,
+        root: None,
+    };
+    Groth16::<Bn254>::circuit_specific_setup(circuit, rng)
+        .expect("MembershipCircuit is well-formed")
+}
+
+/// Description: Produces a zero-knowledge proof that `x` is a member of `acc`, without
+/// revealing `x`. First checks the pairing-based membership equation classically (the
+/// prover must actually hold a valid witness), then builds the Merkle path from `x` to
+/// `acc`'s current member-set root and proves knowledge of it in zero knowledge.
+/// Method: prove_membership
+/// Parameters: acc - the accumulator x claims membership in, x - the member,
+///             witness - a valid membership witness for x, pk - the Groth16 proving key,
+///             rng - randomness source for proof generation
+/// Response: Some(proof) if the witness is valid and `acc` holds at most
+///           `2^MERKLE_DEPTH` members, None otherwise
+pub fn prove_membership<R: RngCore + CryptoRng>(
+    acc: &Bn254Accumulator,
+    x: Fr,
+    witness: ark_bn254::G1Projective,
+    pk: &ProvingKey<Bn254>,
+    rng: &mut R,
+) -> Option<Groth16MembershipProof> {
+    if !acc.verify_membership(x, witness) {
+        return None;
+    }
+
+    let path = merkle_path(&acc.members, x)?;
+    let root = merkle_root(&acc.members)?;
+
+    let circuit = MembershipCircuit {
+        x: Some(x),
+        path: Some(path),
+        root: Some(root),
+    };
+    let proof =
+        Groth16::<Bn254>::prove(pk, circuit, rng).expect("witness satisfies MembershipCircuit");
+
+    Some(Groth16MembershipProof { proof, root })
+}
+
+/// Description: Verifies a zero-knowledge membership proof against the accumulator the
+/// prover claims membership in. Recomputes `acc`'s member-set Merkle root and checks it
+/// matches the one baked into the proof before running the Groth16 verifier, so a proof
+/// cannot be replayed against a different member set, and a prover cannot substitute a
+/// self-chosen root for the real one.
+/// Method: verify_membership_zk
+/// Parameters: vk - the Groth16 verifying key, proof - the proof to check,
+///             acc - the accumulator membership is claimed against
+/// Response: true if the proof is valid for this accumulator's current member set
+pub fn verify_membership_zk(
+    vk: &VerifyingKey<Bn254>,
+    proof: &Groth16MembershipProof,
+    acc: &Bn254Accumulator,
+) -> bool {
+    if Some(proof.root) != merkle_root(&acc.members) {
+        return false;
+    }
+
+    Groth16::<Bn254>::verify(vk, &[proof.root], &proof.proof).unwrap_or(false)
+}
+
+fn fq_to_literal<F: ark_ff::PrimeField>(f: F) -> String {
+    f.into_bigint().to_string()
+}
+
+/// Description: Emits a standalone Solidity contract that verifies Groth16 proofs against
+/// `vk`, following the layout of the verifiers snarkjs/arkworks tooling auto-generates:
+/// the verifying key's `alpha`, `beta`, `gamma`, `delta` and `IC` points are embedded as
+/// constants, the public-input linear combination `vk_x = IC[0] + sum(IC[i+1] * input[i])`
+/// is computed via the `ecAdd`/`ecMul` precompiles at addresses `0x06`/`0x07` (plain
+/// coordinate arithmetic is not EC point addition or scalar multiplication), and
+/// `verifyProof` runs the standard BN254 pairing check via the `ecPairing` precompile at
+/// address `0x08`.
+/// Method: export_solidity_verifier
+/// Parameters: vk - the verifying key to embed
+/// Response: Solidity source for a `Groth16MembershipVerifier` contract
+pub fn export_solidity_verifier(vk: &VerifyingKey<Bn254>) -> String {
+    let alpha = vk.alpha_g1;
+    let beta = vk.beta_g2;
+    let gamma = vk.gamma_g2;
+    let delta = vk.delta_g2;
+    let ic = &vk.gamma_abc_g1;
+
+    let ic_entries: String = ic
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            format!(
+                "        ic[{i}] = Pairing.G1Point({}, {});\n",
+                fq_to_literal(p.x),
+                fq_to_literal(p.y)
+            )
+        })
+        .collect();
+
+    format!(
+        r#"// SPDX-License-Identifier: MIT
+// Auto-generated by ec-accumulator's export_solidity_verifier. Do not edit by hand.
+pragma solidity ^0.8.19;
+
+library Pairing {{
+    struct G1Point {{
+        uint256 x;
+        uint256 y;
+    }}
+
+    struct G2Point {{
+        uint256[2] x;
+        uint256[2] y;
+    }}
+
+    function negate(G1Point memory p) internal pure returns (G1Point memory) {{
+        uint256 q = 21888242871839275222246405745257275088696311157297823662689037894645226208583;
+        if (p.x == 0 && p.y == 0) return G1Point(0, 0);
+        return G1Point(p.x, q - (p.y % q));
+    }}
+
+    /// EC point addition via the ecAdd precompile at 0x06.
+    function addition(G1Point memory p1, G1Point memory p2) internal view returns (G1Point memory r) {{
+        uint256[4] memory input;
+        input[0] = p1.x;
+        input[1] = p1.y;
+        input[2] = p2.x;
+        input[3] = p2.y;
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 6, input, 0x80, r, 0x40)
+        }}
+        require(success, "ecAdd precompile call failed");
+    }}
+
+    /// EC scalar multiplication via the ecMul precompile at 0x07.
+    function scalarMul(G1Point memory p, uint256 s) internal view returns (G1Point memory r) {{
+        uint256[3] memory input;
+        input[0] = p.x;
+        input[1] = p.y;
+        input[2] = s;
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 7, input, 0x60, r, 0x40)
+        }}
+        require(success, "ecMul precompile call failed");
+    }}
+
+    function pairing(G1Point[] memory a, G2Point[] memory b) internal view returns (bool) {{
+        require(a.length == b.length, "pairing length mismatch");
+        uint256 elements = a.length;
+        uint256 inputSize = elements * 6;
+        uint256[] memory input = new uint256[](inputSize);
+        for (uint256 i = 0; i < elements; i++) {{
+            input[i * 6 + 0] = a[i].x;
+            input[i * 6 + 1] = a[i].y;
+            input[i * 6 + 2] = b[i].x[0];
+            input[i * 6 + 3] = b[i].x[1];
+            input[i * 6 + 4] = b[i].y[0];
+            input[i * 6 + 5] = b[i].y[1];
+        }}
+        uint256[1] memory out;
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 8, add(input, 0x20), mul(inputSize, 0x20), out, 0x20)
+        }}
+        require(success, "pairing precompile call failed");
+        return out[0] != 0;
+    }}
+}}
+
+contract Groth16MembershipVerifier {{
+    Pairing.G1Point alpha = Pairing.G1Point({alpha_x}, {alpha_y});
+    Pairing.G2Point beta = Pairing.G2Point([{beta_x1}, {beta_x0}], [{beta_y1}, {beta_y0}]);
+    Pairing.G2Point gamma = Pairing.G2Point([{gamma_x1}, {gamma_x0}], [{gamma_y1}, {gamma_y0}]);
+    Pairing.G2Point delta = Pairing.G2Point([{delta_x1}, {delta_x0}], [{delta_y1}, {delta_y0}]);
+
+    function ic() internal pure returns (Pairing.G1Point[{ic_len}] memory) {{
+        Pairing.G1Point[{ic_len}] memory ic;
+{ic_entries}        return ic;
+    }}
+
+    /// `input` carries this crate's public inputs in order: [root].
+    function verifyProof(
+        uint256[2] memory a,
+        uint256[2][2] memory b,
+        uint256[2] memory c,
+        uint256[{input_len}] memory input
+    ) public view returns (bool) {{
+        Pairing.G1Point[{ic_len}] memory icPoints = ic();
+        Pairing.G1Point memory vkX = icPoints[0];
+        for (uint256 i = 0; i < input.length; i++) {{
+            vkX = Pairing.addition(vkX, Pairing.scalarMul(icPoints[i + 1], input[i]));
+        }}
+
+        Pairing.G1Point[] memory g1Points = new Pairing.G1Point[](4);
+        Pairing.G2Point[] memory g2Points = new Pairing.G2Point[](4);
+
+        g1Points[0] = Pairing.negate(Pairing.G1Point(a[0], a[1]));
+        g2Points[0] = Pairing.G2Point([b[0][0], b[0][1]], [b[1][0], b[1][1]]);
+        g1Points[1] = alpha;
+        g2Points[1] = beta;
+        g1Points[2] = vkX;
+        g2Points[2] = gamma;
+        g1Points[3] = Pairing.G1Point(c[0], c[1]);
+        g2Points[3] = delta;
+
+        return Pairing.pairing(g1Points, g2Points);
+    }}
+}}
+"#,
+        alpha_x = fq_to_literal(alpha.x),
+        alpha_y = fq_to_literal(alpha.y),
+        beta_x0 = fq_to_literal(beta.x.c0),
+        beta_x1 = fq_to_literal(beta.x.c1),
+        beta_y0 = fq_to_literal(beta.y.c0),
+        beta_y1 = fq_to_literal(beta.y.c1),
+        gamma_x0 = fq_to_literal(gamma.x.c0),
+        gamma_x1 = fq_to_literal(gamma.x.c1),
+        gamma_y0 = fq_to_literal(gamma.y.c0),
+        gamma_y1 = fq_to_literal(gamma.y.c1),
+        delta_x0 = fq_to_literal(delta.x.c0),
+        delta_x1 = fq_to_literal(delta.x.c1),
+        delta_y0 = fq_to_literal(delta.y.c0),
+        delta_y1 = fq_to_literal(delta.y.c1),
+        ic_len = ic.len(),
+        ic_entries = ic_entries,
+        input_len = ic.len() - 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::rand::rngs::StdRng;
+    use ark_std::rand::SeedableRng;
+
+    #[test]
+    fn test_zk_membership_round_trip() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        let witness = acc.membership_witness(alice).unwrap();
+
+        let (pk, vk) = setup_membership_circuit(&mut rng);
+        let proof = prove_membership(&acc, alice, witness, &pk, &mut rng).unwrap();
+
+        assert!(verify_membership_zk(&vk, &proof, &acc));
+    }
+
+    #[test]
+    fn test_proof_serialization_round_trip() {
+        let mut rng = StdRng::seed_from_u64(11);
+
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        let witness = acc.membership_witness(alice).unwrap();
+
+        let (pk, vk) = setup_membership_circuit(&mut rng);
+        let proof = prove_membership(&acc, alice, witness, &pk, &mut rng).unwrap();
+
+        let bytes = proof.to_bytes();
+        let restored = Groth16MembershipProof::from_bytes(&bytes).unwrap();
+
+        assert!(verify_membership_zk(&vk, &restored, &acc));
+    }
+
+    #[test]
+    fn test_zk_membership_rejects_invalid_witness() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let acc = Bn254Accumulator::setup(8);
+        let not_a_member = Bn254Accumulator::hash_to_scalar(b"mallory");
+        let forged_witness = acc.g1 * Fr::from(1234u64);
+
+        let (pk, _vk) = setup_membership_circuit(&mut rng);
+        assert!(prove_membership(&acc, not_a_member, forged_witness, &pk, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_merkle_path_requires_actual_member() {
+        // Closes the forgeability gap the old fingerprint-commitment relation left open:
+        // a non-member has no path to the real root, full stop, regardless of what `k` or
+        // commitment a prover might otherwise have been free to choose.
+        let mut acc = Bn254Accumulator::setup(8);
+        acc.add_member(b"alice").unwrap();
+
+        let mallory = Bn254Accumulator::hash_to_scalar(b"mallory");
+        assert!(merkle_path(&acc.members, mallory).is_none());
+    }
+
+    #[test]
+    fn test_merkle_root_and_path_reject_oversized_member_list() {
+        // A member list larger than the tree's 2^MERKLE_DEPTH leaf capacity must be
+        // rejected outright, not silently truncated by merkle_leaves (which would let
+        // merkle_path index past the end of the truncated level and panic).
+        let too_many: Vec<Fr> = (0..(1usize << MERKLE_DEPTH) + 1)
+            .map(|i| Bn254Accumulator::hash_to_scalar(format!("member-{i}").as_bytes()))
+            .collect();
+
+        assert!(merkle_root(&too_many).is_none());
+        assert!(merkle_path(&too_many, too_many[0]).is_none());
+    }
+
+    #[test]
+    fn test_solidity_verifier_matches_public_input_shape() {
+        let mut rng = StdRng::seed_from_u64(99);
+        let (_pk, vk) = setup_membership_circuit(&mut rng);
+
+        let solidity = export_solidity_verifier(&vk);
+        assert!(solidity.contains("function verifyProof"));
+        // [root] -> one public input, one IC entry per input plus one.
+        assert!(solidity.contains("uint256[1] memory input"));
+        assert!(solidity.contains("Pairing.G1Point[2] memory"));
+        // vk_x must be a real EC point addition/scalar-mult via the ecAdd/ecMul
+        // precompiles, not independent coordinate arithmetic.
+        assert!(solidity.contains("staticcall(sub(gas(), 2000), 6,"));
+        assert!(solidity.contains("staticcall(sub(gas(), 2000), 7,"));
+        assert!(solidity.contains("Pairing.addition(vkX, Pairing.scalarMul(icPoints[i + 1], input[i]))"));
+    }
+}
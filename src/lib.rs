@@ -1,26 +1,94 @@
 use ark_bn254::{Bn254, Fr, G1Projective, G2Projective};
-use ark_ec::{pairing::Pairing, PrimeGroup};
+use ark_ec::pairing::Pairing;
+use ark_ec::{CurveGroup, PrimeGroup, VariableBaseMSM};
 use ark_ff::{Field, PrimeField};
+use ark_std::{rand::thread_rng, UniformRand};
 use tiny_keccak::{Hasher, Keccak};
 
+pub mod ffi;
+pub mod groth16;
+pub mod rln;
+pub(crate) mod serialize;
+
 /**
- * Description: This struct implements a simple accumulator using the Bn254 curve.
+ * Description: This struct implements a trapdoor (Nguyen-style) accumulator over the
+ * Bn254 pairing, as opposed to the naive `acc *= x` scheme it replaces. The accumulator
+ * value is `acc = g1^{prod(s + x_i)}` for a hidden scalar `s`, and membership is checked
+ * via a pairing equation rather than by trusting whoever computed the witness.
+ *
+ * The struct never stores `s` itself. Instead it stores the powers-of-tau style
+ * structured reference string `powers[i] = g1^{s^i}` and the single element
+ * `g2s = g2^s`, which together are enough to evaluate `prod(s + x_i)` "in the exponent"
+ * for any member set, without anyone needing to know `s`.
  */
 pub struct Bn254Accumulator {
     pub g1: G1Projective,
     pub g2: G2Projective,
+    pub g2s: G2Projective,
+    pub powers: Vec<G1Projective>,
     pub acc: G1Projective,
     pub members: Vec<Fr>,
 }
 
 impl Bn254Accumulator {
-    pub fn new() -> Self {
+    /**
+     * Description: Creates a new accumulator by sampling a fresh trapdoor `s` in-process
+     * and deriving the powers-of-tau SRS from it. This is convenient for tests and local
+     * development, but `s` exists transiently in process memory while the SRS is built,
+     * so it must not be used for anything where soundness depends on nobody having seen
+     * `s`. Production deployments should use `setup_from_powers_of_tau` with an SRS from
+     * an external MPC ceremony instead.
+     * Method: setup
+     * Parameters: degree - the maximum number of members the accumulator can hold
+     * Response: A new Bn254Accumulator with an empty member set
+     */
+    pub fn setup(degree: usize) -> Self {
+        let mut rng = thread_rng();
+        let s = Fr::rand(&mut rng);
         let g1 = G1Projective::generator();
         let g2 = G2Projective::generator();
+
+        let mut powers = Vec::with_capacity(degree + 1);
+        let mut s_pow = Fr::ONE;
+        for _ in 0..=degree {
+            powers.push(g1 * s_pow);
+            s_pow *= s;
+        }
+        let g2s = g2 * s;
+
         Self {
             g1,
             g2,
-            acc: g1,
+            g2s,
+            acc: powers[0],
+            powers,
+            members: Vec::new(),
+        }
+    }
+
+    /**
+     * Description: Creates a new accumulator from an externally generated powers-of-tau
+     * SRS (e.g. the output of a multi-party ceremony), so the trapdoor `s` never needs to
+     * exist in one place. Only the public powers `{g1^{s^i}}` and `g2^s` are required.
+     * Method: setup_from_powers_of_tau
+     * Parameters: powers - the G1 power basis `{g1^{s^i}}` for i = 0..=degree,
+     *             g2s - the G2 element `g2^s`
+     * Response: A new Bn254Accumulator with an empty member set
+     */
+    pub fn setup_from_powers_of_tau(powers: Vec<G1Projective>, g2s: G2Projective) -> Self {
+        assert!(
+            !powers.is_empty(),
+            "powers-of-tau SRS must contain at least g1^(s^0)"
+        );
+        let g1 = powers[0];
+        let g2 = G2Projective::generator();
+
+        Self {
+            g1,
+            g2,
+            g2s,
+            acc: powers[0],
+            powers,
             members: Vec::new(),
         }
     }
@@ -40,54 +108,383 @@ impl Bn254Accumulator {
     }
 
     /**
-     * Description: Adds a member to the accumulator.
+     * Description: Expands `prod(X + r)` over the given roots into coefficient form,
+     * lowest degree first. Used to turn a member set into the characteristic polynomial
+     * that gets committed against the power basis.
+     * Method: poly_from_roots
+     * Parameters: roots - the roots `r` of the polynomial
+     * Response: The coefficients of the resulting polynomial, `coeffs[i]` being the
+     *           coefficient of `X^i`
+     */
+    fn poly_from_roots(roots: &[Fr]) -> Vec<Fr> {
+        let mut coeffs = vec![Fr::ONE];
+        for &r in roots {
+            let mut next = vec![Fr::ZERO; coeffs.len() + 1];
+            for (i, c) in coeffs.iter().enumerate() {
+                next[i] += *c * r;
+                next[i + 1] += *c;
+            }
+            coeffs = next;
+        }
+        coeffs
+    }
+
+    /**
+     * Description: Commits to a polynomial (given in coefficient form) by evaluating it
+     * "in the exponent" against the stored power basis, via a multi-scalar multiplication.
+     * Method: commit
+     * Parameters: coeffs - the coefficients of the polynomial, lowest degree first
+     * Response: `g1^{poly(s)}`
+     */
+    fn commit(&self, coeffs: &[Fr]) -> G1Projective {
+        assert!(
+            coeffs.len() <= self.powers.len(),
+            "characteristic polynomial degree exceeds the SRS size; setup with a larger degree"
+        );
+        let bases = G1Projective::normalize_batch(&self.powers[..coeffs.len()]);
+        G1Projective::msm(&bases, coeffs).expect("bases and scalars have matching length")
+    }
+
+    /**
+     * Description: Adds a member to the accumulator, recomputing `acc` as the commitment
+     * to the characteristic polynomial of the new member set.
      * Method: add_member
      * Parameters: member - the member to be added
-     * Response: The scalar value of the member
+     * Response: Some(scalar value of the member), or None if the accumulator is already
+     *           at its SRS-bounded capacity (`setup`'s `degree`)
      */
-    pub fn add_member(&mut self, member: &[u8]) -> Fr {
+    pub fn add_member(&mut self, member: &[u8]) -> Option<Fr> {
         let x = Self::hash_to_scalar(member);
-        self.acc *= x;
+        self.add_member_scalar(x)?;
+        Some(x)
+    }
+
+    /**
+     * Description: Adds a member given directly as a scalar, skipping the
+     * `hash_to_scalar` step `add_member` performs on raw bytes. Used where the member
+     * value is itself already a commitment computed elsewhere (e.g. the RLN identity
+     * commitments in the `rln` module), so the accumulated leaf matches exactly what a
+     * ZK circuit recomputes.
+     * Method: add_member_scalar
+     * Parameters: x - the member scalar to add
+     * Response: Some(()), or None if the accumulator is already at its SRS-bounded
+     *           capacity (`setup`'s `degree`)
+     */
+    pub fn add_member_scalar(&mut self, x: Fr) -> Option<()> {
+        if self.members.len() >= self.powers.len().saturating_sub(1) {
+            return None;
+        }
         self.members.push(x);
-        x
+        self.acc = self.commit(&Self::poly_from_roots(&self.members));
+        Some(())
     }
 
     /**
-     * Description: Calculates the witness for verifying membership proof of a particular member.
+     * Description: Calculates the witness for verifying membership proof of a particular
+     * member, as the commitment to `prod_{j != i}(s + x_j)`.
      * Method: membership_witness
      * Parameters: member whose witness needs to be calculated
      * Response: witness for verifying the inclusion of particular member
      */
     pub fn membership_witness(&self, x: Fr) -> Option<G1Projective> {
-        // Compute product of all x_i except x
-        let mut product = Fr::ONE;
         let mut found = false;
-        for xi in &self.members {
-            if *xi == x && !found {
-                found = true; // skip only the first occurrence
-                continue;
-            }
-            product *= xi;
-        }
+        let others: Vec<Fr> = self
+            .members
+            .iter()
+            .copied()
+            .filter(|xi| {
+                if *xi == x && !found {
+                    found = true; // skip only the first occurrence
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect();
 
         if found {
-            Some(self.g1 * product)
+            Some(self.commit(&Self::poly_from_roots(&others)))
         } else {
             None
         }
     }
 
     /**
-     * Description: Verifies the membership of a member in the accumulator.
+     * Description: Verifies the membership of a member in the accumulator via the
+     * pairing equation `e(w, g2^s * g2^x) == e(acc, g2)`.
      * Method: verify_membership
      * Parameters: x - the member to be verified, witness - the witness for the member
      * Response: true if the member is in the accumulator, false otherwise
      */
     pub fn verify_membership(&self, x: Fr, witness: G1Projective) -> bool {
-        let lhs = Bn254::pairing(witness * x, self.g2);
+        let lhs = Bn254::pairing(witness, self.g2s + self.g2 * x);
+        let rhs = Bn254::pairing(self.acc, self.g2);
+        lhs == rhs
+    }
+
+    /**
+     * Description: Divides the polynomial `dividend` (coefficients lowest-degree first)
+     * by the linear factor `(X - root)` via synthetic division, returning the quotient
+     * (lowest-degree first) and the remainder. By the polynomial remainder theorem the
+     * remainder equals `dividend(root)`.
+     * Method: synthetic_divide
+     * Parameters: dividend - coefficients of the polynomial being divided,
+     *             root - the root of the linear divisor `(X - root)`
+     * Response: (quotient coefficients, remainder)
+     */
+    fn synthetic_divide(dividend: &[Fr], root: Fr) -> (Vec<Fr>, Fr) {
+        if dividend.is_empty() {
+            return (Vec::new(), Fr::ZERO);
+        }
+
+        let n = dividend.len();
+        let mut quotient = vec![Fr::ZERO; n - 1];
+        let mut acc = dividend[n - 1];
+        for i in (0..n - 1).rev() {
+            quotient[i] = acc;
+            acc = dividend[i] + root * acc;
+        }
+        (quotient, acc)
+    }
+
+    /**
+     * Description: Computes a non-membership witness for `y`, mirroring the Bezout-style
+     * non-membership proofs used in RSA accumulators. Lets `P(X) = prod(X + x_i)` be the
+     * characteristic polynomial of the member set; dividing `P(X)` by `(X + y)` yields
+     * `P(X) = Q(X)*(X + y) + r`, where `r = P(-y)`. If `y` is not a member then `r != 0`;
+     * if `y` is a member then `(X + y)` divides `P` exactly and `r == 0`.
+     * Method: non_membership_witness
+     * Parameters: y - the value to produce a non-membership witness for
+     * Response: (W = g1^{Q(s)}, r), the witness and the nonzero-iff-non-member remainder
+     */
+    pub fn non_membership_witness(&self, y: Fr) -> (G1Projective, Fr) {
+        let poly = Self::poly_from_roots(&self.members);
+        let (quotient, remainder) = Self::synthetic_divide(&poly, -y);
+        (self.commit(&quotient), remainder)
+    }
+
+    /**
+     * Description: Verifies a non-membership witness for `y` via
+     * `e(W, g2^s * g2^y) * e(g1, g2)^r == e(acc, g2)`, rejecting outright if `r == 0`
+     * since that would only happen for an actual member.
+     * Method: verify_non_membership
+     * Parameters: y - the value the witness claims is not a member,
+     *             witness - the (W, r) pair produced by non_membership_witness
+     * Response: true if `y` is demonstrably not a member of the accumulator
+     */
+    pub fn verify_non_membership(&self, y: Fr, witness: (G1Projective, Fr)) -> bool {
+        let (w, r) = witness;
+        if r == Fr::ZERO {
+            return false;
+        }
+
+        let lhs = Bn254::pairing(w, self.g2s + self.g2 * y) + Bn254::pairing(self.g1, self.g2) * r;
         let rhs = Bn254::pairing(self.acc, self.g2);
         lhs == rhs
     }
+
+    /**
+     * Description: Removes a member from the accumulator, recomputing `acc` as the
+     * commitment to the characteristic polynomial of the remaining member set. This is
+     * the trapdoor-design analogue of raising the accumulator to `1/(s+x)`: since we hold
+     * the power basis rather than `s`, we recompute the commitment directly instead of
+     * dividing the exponent.
+     * Method: delete_member
+     * Parameters: x - the member to remove
+     * Response: Some(()) if `x` was a member and has been removed, None otherwise
+     */
+    pub fn delete_member(&mut self, x: Fr) -> Option<()> {
+        let pos = self.members.iter().position(|&xi| xi == x)?;
+        self.members.remove(pos);
+        self.acc = self.commit(&Self::poly_from_roots(&self.members));
+        Some(())
+    }
+
+    /**
+     * Description: Applies a single membership-witness update in place, so an existing
+     * witness holder can refresh their witness without recomputing it from the full
+     * member set. The update formulas each reference the accumulator value at a specific
+     * point in time relative to the change (the value immediately before an addition, or
+     * immediately after a deletion), so that snapshot travels with the `Change` itself
+     * rather than being read off `self` - that keeps this method correct no matter what
+     * state `self.acc` happens to be in when it's called.
+     * Method: update_witness
+     * Parameters: w - the witness to update, in place,
+     *             x - the member the witness is for,
+     *             change - the addition or deletion being applied, carrying the
+     *             accumulator snapshot the update formula needs
+     * Response: Ok(()) on success, or Err if `change` deletes the witness's own member
+     *           (a witness cannot be updated across the deletion of the member it
+     *           attests to - it simply no longer applies)
+     */
+    pub fn update_witness(
+        &self,
+        w: &mut G1Projective,
+        x: Fr,
+        change: Change,
+    ) -> Result<(), WitnessUpdateError> {
+        match change {
+            Change::Addition { member, acc_before } => {
+                // w_new = acc_before + (member - x) * w_old
+                *w = acc_before + *w * (member - x);
+                Ok(())
+            }
+            Change::Deletion { member, acc_after } => {
+                if member == x {
+                    return Err(WitnessUpdateError::OwnElementDeleted);
+                }
+                // w_new = (w_old - acc_after) / (member - x)
+                let inv = (member - x)
+                    .inverse()
+                    .expect("member != x was just checked, so this is nonzero");
+                *w = (*w - acc_after) * inv;
+                Ok(())
+            }
+        }
+    }
+
+    /**
+     * Description: Applies a sequence of membership-witness updates in place, as produced
+     * by changes to the member set since the witness was last current.
+     * Method: batch_update_witness
+     * Parameters: w - the witness to update, in place,
+     *             x - the member the witness is for,
+     *             changes - the ordered sequence of additions/deletions to apply
+     * Response: Ok(()) if every change applied successfully, or the first Err encountered
+     */
+    pub fn batch_update_witness(
+        &self,
+        w: &mut G1Projective,
+        x: Fr,
+        changes: &[Change],
+    ) -> Result<(), WitnessUpdateError> {
+        for change in changes {
+            self.update_witness(w, x, *change)?;
+        }
+        Ok(())
+    }
+
+    /**
+     * Description: Serializes the accumulator's full state to the crate's fixed-width
+     * big-endian wire format, so it can be persisted or handed to a non-Rust caller (see
+     * the `ffi` module) and later restored with `from_bytes`.
+     * Method: to_bytes
+     * Parameters: none
+     * Response: The serialized accumulator
+     */
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&serialize::g1_to_be_bytes(self.g1));
+        out.extend_from_slice(&serialize::g2_to_be_bytes(self.g2));
+        out.extend_from_slice(&serialize::g2_to_be_bytes(self.g2s));
+
+        out.extend_from_slice(&(self.powers.len() as u64).to_be_bytes());
+        for p in &self.powers {
+            out.extend_from_slice(&serialize::g1_to_be_bytes(*p));
+        }
+
+        out.extend_from_slice(&(self.members.len() as u64).to_be_bytes());
+        for x in &self.members {
+            out.extend_from_slice(&serialize::fr_to_be_bytes(*x));
+        }
+
+        out.extend_from_slice(&serialize::g1_to_be_bytes(self.acc));
+        out
+    }
+
+    /**
+     * Description: Restores an accumulator previously serialized with `to_bytes`.
+     * Witnesses issued against the original accumulator remain valid against the
+     * restored one, since all of its state (including the SRS power basis) round-trips.
+     * Method: from_bytes
+     * Parameters: bytes - the serialized accumulator
+     * Response: The restored accumulator, or a DecodeError if `bytes` is malformed
+     */
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, serialize::DecodeError> {
+        let mut r = serialize::Reader::new(bytes);
+
+        let g1 = serialize::g1_from_be_bytes(r.take(serialize::G1_BYTES)?)?;
+        let g2 = serialize::g2_from_be_bytes(r.take(serialize::G2_BYTES)?)?;
+        let g2s = serialize::g2_from_be_bytes(r.take(serialize::G2_BYTES)?)?;
+
+        let powers_len = r.take_u64()? as usize;
+        if powers_len.saturating_mul(serialize::G1_BYTES) > r.remaining() {
+            return Err(serialize::DecodeError::UnexpectedLength);
+        }
+        let mut powers = Vec::with_capacity(powers_len);
+        for _ in 0..powers_len {
+            powers.push(serialize::g1_from_be_bytes(r.take(serialize::G1_BYTES)?)?);
+        }
+
+        let members_len = r.take_u64()? as usize;
+        // The power basis bounds the member count (see `add_member_scalar`); more members
+        // than that could not be committed to later.
+        if members_len >= powers_len {
+            return Err(serialize::DecodeError::UnexpectedLength);
+        }
+        if members_len.saturating_mul(serialize::FR_BYTES) > r.remaining() {
+            return Err(serialize::DecodeError::UnexpectedLength);
+        }
+        let mut members = Vec::with_capacity(members_len);
+        for _ in 0..members_len {
+            members.push(serialize::fr_from_be_bytes(r.take(serialize::FR_BYTES)?)?);
+        }
+
+        let acc = serialize::g1_from_be_bytes(r.take(serialize::G1_BYTES)?)?;
+
+        Ok(Self {
+            g1,
+            g2,
+            g2s,
+            powers,
+            acc,
+            members,
+        })
+    }
+}
+
+/**
+ * Description: Serializes a membership witness to the crate's fixed-width big-endian
+ * wire format. A witness is just a G1 point, but since `G1Projective` is defined in
+ * `ark_bn254` rather than this crate, it can't carry inherent `to_bytes`/`from_bytes`
+ * methods of its own (the orphan rule), so these are free functions instead.
+ * Method: witness_to_bytes
+ * Parameters: witness - the witness to serialize
+ * Response: The serialized witness
+ */
+pub fn witness_to_bytes(witness: &G1Projective) -> Vec<u8> {
+    serialize::g1_to_be_bytes(*witness).to_vec()
+}
+
+/**
+ * Description: Restores a membership witness previously serialized with
+ * `witness_to_bytes`.
+ * Method: witness_from_bytes
+ * Parameters: bytes - the serialized witness
+ * Response: The restored witness, or a DecodeError if `bytes` is malformed
+ */
+pub fn witness_from_bytes(bytes: &[u8]) -> Result<G1Projective, serialize::DecodeError> {
+    serialize::g1_from_be_bytes(bytes)
+}
+
+/**
+ * Description: A single addition or deletion applied to an accumulator's member set,
+ * carrying the accumulator snapshot (`acc_before` for an addition, `acc_after` for a
+ * deletion) that `update_witness` needs to refresh an existing witness.
+ */
+#[derive(Debug, Clone, Copy)]
+pub enum Change {
+    Addition { member: Fr, acc_before: G1Projective },
+    Deletion { member: Fr, acc_after: G1Projective },
+}
+
+/**
+ * Description: The ways a membership-witness update can fail.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WitnessUpdateError {
+    /// The change being applied deletes the very member the witness attests to.
+    OwnElementDeleted,
 }
 
 #[cfg(test)]
@@ -96,10 +493,10 @@ mod tests {
 
     #[test]
     fn test_membership_proof() {
-        let mut acc = Bn254Accumulator::new();
+        let mut acc = Bn254Accumulator::setup(8);
 
         let members: Vec<&[u8]> = vec![b"alice", b"bob", b"charlie"];
-        let scalars: Vec<Fr> = members.iter().map(|m| acc.add_member(*m)).collect();
+        let scalars: Vec<Fr> = members.iter().map(|m| acc.add_member(*m).unwrap()).collect();
 
         for (i, x) in scalars.iter().enumerate() {
             let witness = acc.membership_witness(*x).unwrap();
@@ -110,14 +507,207 @@ mod tests {
 
     #[test]
     fn test_non_member_should_fail() {
-        let mut acc = Bn254Accumulator::new();
+        let mut acc = Bn254Accumulator::setup(8);
 
-        let _ = acc.add_member(b"alice");
-        let _ = acc.add_member(b"bob");
+        let _ = acc.add_member(b"alice").unwrap();
+        let _ = acc.add_member(b"bob").unwrap();
 
         let fake = Bn254Accumulator::hash_to_scalar(b"mallory");
         let fake_witness = acc.membership_witness(fake);
 
         assert!(fake_witness.is_none(), "Non-member should not have a witness");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_forged_witness_is_rejected() {
+        // Unlike the old `acc *= x` scheme, a witness can no longer be forged without
+        // knowledge of the trapdoor: an arbitrary G1 element must not verify.
+        let mut acc = Bn254Accumulator::setup(8);
+        let x = acc.add_member(b"alice").unwrap();
+
+        let forged_witness = acc.g1 * Fr::from(1234u64);
+        assert!(!acc.verify_membership(x, forged_witness));
+    }
+
+    #[test]
+    fn test_non_membership_proof() {
+        let mut acc = Bn254Accumulator::setup(8);
+        acc.add_member(b"alice").unwrap();
+        acc.add_member(b"bob").unwrap();
+
+        let mallory = Bn254Accumulator::hash_to_scalar(b"mallory");
+        let witness = acc.non_membership_witness(mallory);
+
+        assert_ne!(witness.1, Fr::ZERO, "non-member should have a nonzero remainder");
+        assert!(acc.verify_non_membership(mallory, witness));
+    }
+
+    #[test]
+    fn test_non_membership_rejects_actual_member() {
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        acc.add_member(b"bob").unwrap();
+
+        let witness = acc.non_membership_witness(alice);
+        assert_eq!(witness.1, Fr::ZERO, "a member's remainder must be zero");
+        assert!(!acc.verify_non_membership(alice, witness));
+    }
+
+    #[test]
+    fn test_delete_member_updates_accumulator() {
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        acc.add_member(b"bob").unwrap();
+
+        assert!(acc.delete_member(alice).is_some());
+        assert!(acc.membership_witness(alice).is_none());
+        assert!(acc.delete_member(alice).is_none(), "deleting twice should be a no-op");
+
+        let bob = acc.members[0];
+        let witness = acc.membership_witness(bob).unwrap();
+        assert!(acc.verify_membership(bob, witness));
+    }
+
+    #[test]
+    fn test_update_witness_on_addition() {
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        let mut witness = acc.membership_witness(alice).unwrap();
+
+        let acc_before = acc.acc;
+        let bob = acc.add_member(b"bob").unwrap();
+
+        acc.update_witness(
+            &mut witness,
+            alice,
+            Change::Addition {
+                member: bob,
+                acc_before,
+            },
+        )
+        .unwrap();
+
+        assert!(acc.verify_membership(alice, witness));
+    }
+
+    #[test]
+    fn test_update_witness_on_deletion() {
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        let bob = acc.add_member(b"bob").unwrap();
+        let mut witness = acc.membership_witness(alice).unwrap();
+
+        acc.delete_member(bob).unwrap();
+        let acc_after = acc.acc;
+
+        acc.update_witness(
+            &mut witness,
+            alice,
+            Change::Deletion {
+                member: bob,
+                acc_after,
+            },
+        )
+        .unwrap();
+
+        assert!(acc.verify_membership(alice, witness));
+    }
+
+    #[test]
+    fn test_update_witness_rejects_own_deletion() {
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        let mut witness = acc.membership_witness(alice).unwrap();
+
+        acc.delete_member(alice).unwrap();
+        let err = acc
+            .update_witness(
+                &mut witness,
+                alice,
+                Change::Deletion {
+                    member: alice,
+                    acc_after: acc.acc,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(err, WitnessUpdateError::OwnElementDeleted);
+    }
+
+    #[test]
+    fn test_batch_update_witness() {
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        let mut witness = acc.membership_witness(alice).unwrap();
+
+        let acc_before_bob = acc.acc;
+        let bob = acc.add_member(b"bob").unwrap();
+        acc.delete_member(bob).unwrap();
+        let acc_after_bob_deleted = acc.acc;
+
+        acc.batch_update_witness(
+            &mut witness,
+            alice,
+            &[
+                Change::Addition {
+                    member: bob,
+                    acc_before: acc_before_bob,
+                },
+                Change::Deletion {
+                    member: bob,
+                    acc_after: acc_after_bob_deleted,
+                },
+            ],
+        )
+        .unwrap();
+
+        assert!(acc.verify_membership(alice, witness));
+    }
+
+    #[test]
+    fn test_setup_from_powers_of_tau() {
+        // An accumulator built from an externally supplied SRS behaves identically to
+        // one built via `setup`.
+        let seed = Bn254Accumulator::setup(8);
+        let mut acc = Bn254Accumulator::setup_from_powers_of_tau(seed.powers.clone(), seed.g2s);
+
+        let x = acc.add_member(b"alice").unwrap();
+        let witness = acc.membership_witness(x).unwrap();
+        assert!(acc.verify_membership(x, witness));
+    }
+
+    #[test]
+    fn test_serialization_round_trip_preserves_witnesses() {
+        let mut acc = Bn254Accumulator::setup(8);
+        let alice = acc.add_member(b"alice").unwrap();
+        acc.add_member(b"bob").unwrap();
+        let witness = acc.membership_witness(alice).unwrap();
+
+        let bytes = acc.to_bytes();
+        let restored = Bn254Accumulator::from_bytes(&bytes).unwrap();
+
+        assert!(restored.verify_membership(alice, witness));
+
+        let witness_bytes = witness_to_bytes(&witness);
+        let restored_witness = witness_from_bytes(&witness_bytes).unwrap();
+        assert!(restored.verify_membership(alice, restored_witness));
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_oversized_length_prefix() {
+        // A malicious or corrupt `powers_len`/`members_len` prefix must be rejected with a
+        // DecodeError, not drive an allocation sized off attacker-controlled data.
+        let mut acc = Bn254Accumulator::setup(8);
+        acc.add_member(b"alice").unwrap();
+        let mut bytes = acc.to_bytes();
+
+        let powers_len_offset = serialize::G1_BYTES + 2 * serialize::G2_BYTES;
+        bytes[powers_len_offset..powers_len_offset + 8]
+            .copy_from_slice(&u64::MAX.to_be_bytes());
+
+        assert_eq!(
+            Bn254Accumulator::from_bytes(&bytes).unwrap_err(),
+            serialize::DecodeError::UnexpectedLength
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Rate-limiting nullifier (RLN) mode layered on top of [`Bn254Accumulator`], for
+//! anti-spam use cases: each member registers an identity commitment, and signalling more
+//! than once per epoch leaks enough information for anyone to recover that member's
+//! identity secret.
+//!
+//! A share is only accepted alongside a ZK proof (`RlnCircuit`) that it was honestly
+//! derived from the identity secret behind a real, currently-registered identity
+//! commitment: the circuit recomputes the commitment, walks a Merkle path to the
+//! accumulator's member-set root, and re-derives `share_y` and `nullifier` from the same
+//! secret - so a verifier never sees the identity commitment or the secret, only the
+//! proof and its public inputs.
+
+use crate::groth16;
+use crate::Bn254Accumulator;
+use ark_bn254::{Bn254, Fr};
+use ark_ff::Field;
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snark::SNARK;
+use ark_std::rand::{CryptoRng, RngCore};
+
+fn identity_domain() -> Fr {
+    Bn254Accumulator::hash_to_scalar(b"ec-accumulator-rln-identity")
+}
+
+fn nullifier_domain() -> Fr {
+    Bn254Accumulator::hash_to_scalar(b"ec-accumulator-rln-nullifier")
+}
+
+/// Description: Derives the public identity commitment for an identity secret, via the
+/// same MiMC hash the `RlnCircuit` recomputes in-circuit. Registering a member is
+/// `acc.add_member_scalar(identity_commitment(identity_secret))`.
+/// Method: identity_commitment
+/// Parameters: identity_secret - the member's private identity secret `a_0`
+/// Response: The identity commitment accumulated on the member's behalf
+pub fn identity_commitment(identity_secret: Fr) -> Fr {
+    groth16::mimc_hash(identity_secret, identity_domain())
+}
+
+fn shamir_slope(identity_secret: Fr, epoch: Fr) -> Fr {
+    groth16::mimc_hash(identity_secret, epoch)
+}
+
+/// Proves, in zero knowledge, that `share_y` and `nullifier` were honestly derived from
+/// the identity secret behind a real identity commitment registered in the accumulator's
+/// current member-set root - without revealing the identity secret, the commitment, or
+/// which member it belongs to.
+struct RlnCircuit {
+    a0: Option<Fr>,
+    path: Option<Vec<(Fr, bool)>>,
+    root: Option<Fr>,
+    epoch: Option<Fr>,
+    share_x: Option<Fr>,
+    share_y: Option<Fr>,
+    nullifier: Option<Fr>,
+}
+
+impl ConstraintSynthesizer<Fr> for RlnCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let root = FpVar::new_input(cs.clone(), || self.root.ok_or(SynthesisError::AssignmentMissing))?;
+        let epoch = FpVar::new_input(cs.clone(), || self.epoch.ok_or(SynthesisError::AssignmentMissing))?;
+        let share_x = FpVar::new_input(cs.clone(), || self.share_x.ok_or(SynthesisError::AssignmentMissing))?;
+        let share_y = FpVar::new_input(cs.clone(), || self.share_y.ok_or(SynthesisError::AssignmentMissing))?;
+        let nullifier =
+            FpVar::new_input(cs.clone(), || self.nullifier.ok_or(SynthesisError::AssignmentMissing))?;
+
+        let a0 = FpVar::new_witness(cs.clone(), || self.a0.ok_or(SynthesisError::AssignmentMissing))?;
+
+        let identity_domain = FpVar::constant(identity_domain());
+        let ic = groth16::mimc_gadget(&a0, &identity_domain)?;
+        let computed_root = groth16::merkle_root_gadget(cs.clone(), &ic, &self.path)?;
+        computed_root.enforce_equal(&root)?;
+
+        // a_1 = mimc(a_0, epoch); share_y = a_0 + a_1 * share_x, the degree-1 Shamir
+        // polynomial evaluated at share_x.
+        let a1 = groth16::mimc_gadget(&a0, &epoch)?;
+        let computed_share_y = &a0 + &a1 * &share_x;
+        computed_share_y.enforce_equal(&share_y)?;
+
+        let nullifier_domain = FpVar::constant(nullifier_domain());
+        let computed_nullifier = groth16::mimc_gadget(&a1, &nullifier_domain)?;
+        computed_nullifier.enforce_equal(&nullifier)?;
+
+        Ok(())
+    }
+}
+
+/// An RLN proof: a Groth16 proof plus the public inputs it was generated against.
+pub struct RlnProof {
+    pub proof: Proof<Bn254>,
+    pub root: Fr,
+    pub epoch: Fr,
+    pub share_x: Fr,
+    pub share_y: Fr,
+    pub nullifier: Fr,
+}
+
+/// Description: Runs the (circuit-specific) Groth16 trusted setup for `RlnCircuit`,
+/// producing a proving/verifying key pair.
+/// Method: setup_rln_circuit
+/// Parameters: rng - randomness source for the setup
+/// Response: (proving key, verifying key)
+pub fn setup_rln_circuit<R: RngCore + CryptoRng>(rng: &mut R) -> (ProvingKey<Bn254>, VerifyingKey<Bn254>) {
+    let circuit = RlnCircuit {
+        a0: None,
+        path: None,
+        root: None,
+        epoch: None,
+        share_x: None,
+        share_y: None,
+        nullifier: None,
+    };
+    Groth16::<Bn254>::circuit_specific_setup(circuit, rng).expect("RlnCircuit is well-formed")
+}
+
+/// Description: Evaluates the member's degree-1 Shamir polynomial `A(x) = a_0 + a_1*x`
+/// (with `a_1 = mimc(a_0, epoch)`) at `x = hash(signal)`, then proves in zero knowledge
+/// that the resulting share and nullifier were honestly derived from the identity secret
+/// behind a real, registered identity commitment.
+/// Method: rln_prove
+/// Parameters: acc - the accumulator the identity is registered in,
+///             identity_secret - the member's private identity secret `a_0`,
+///             epoch - the current epoch, scoping how often a member may signal,
+///             signal - the message being signalled,
+///             pk - the Groth16 proving key for RlnCircuit,
+///             rng - randomness source for proof generation
+/// Response: Some(proof) if the identity is registered in `acc` and `acc` holds at most
+///           `2^MERKLE_DEPTH` members, None otherwise
+pub fn rln_prove<R: RngCore + CryptoRng>(
+    acc: &Bn254Accumulator,
+    identity_secret: Fr,
+    epoch: Fr,
+    signal: &[u8],
+    pk: &ProvingKey<Bn254>,
+    rng: &mut R,
+) -> Option<RlnProof> {
+    let commitment = identity_commitment(identity_secret);
+    let path = groth16::merkle_path(&acc.members, commitment)?;
+    let root = groth16::merkle_root(&acc.members)?;
+
+    let a1 = shamir_slope(identity_secret, epoch);
+    let share_x = Bn254Accumulator::hash_to_scalar(signal);
+    let share_y = identity_secret + a1 * share_x;
+    let nullifier = groth16::mimc_hash(a1, nullifier_domain());
+
+    let circuit = RlnCircuit {
+        a0: Some(identity_secret),
+        path: Some(path),
+        root: Some(root),
+        epoch: Some(epoch),
+        share_x: Some(share_x),
+        share_y: Some(share_y),
+        nullifier: Some(nullifier),
+    };
+    let proof = Groth16::<Bn254>::prove(pk, circuit, rng).expect("witness satisfies RlnCircuit");
+
+    Some(RlnProof {
+        proof,
+        root,
+        epoch,
+        share_x,
+        share_y,
+        nullifier,
+    })
+}
+
+/// Description: Verifies an RLN proof: the share's `x` coordinate must be the hash of the
+/// claimed signal, the proof's root must match `acc`'s current member-set root, and the
+/// Groth16 proof itself must verify - which, per `RlnCircuit`, is only possible for a
+/// prover who actually holds the identity secret behind a registered identity commitment
+/// and derived `share_y`/`nullifier` honestly from it. Unlike a cleartext check, no
+/// identity commitment ever needs to be named.
+/// Method: rln_verify
+/// Parameters: vk - the Groth16 verifying key for RlnCircuit,
+///             acc - the accumulator the prover claims membership in,
+///             signal - the signal the share claims to be for,
+///             proof - the RLN proof, as produced by rln_prove
+/// Response: true if the share is well-formed and the identity is a registered member
+pub fn rln_verify(vk: &VerifyingKey<Bn254>, acc: &Bn254Accumulator, signal: &[u8], proof: &RlnProof) -> bool {
+    if Some(proof.root) != groth16::merkle_root(&acc.members) {
+        return false;
+    }
+    if proof.share_x != Bn254Accumulator::hash_to_scalar(signal) {
+        return false;
+    }
+
+    let public_inputs = [proof.root, proof.epoch, proof.share_x, proof.share_y, proof.nullifier];
+    Groth16::<Bn254>::verify(vk, &public_inputs, &proof.proof).unwrap_or(false)
+}
+
+/// Description: Recovers a spammer's identity secret `a_0` from two shares produced in
+/// the same epoch. Two distinct points on the same degree-1 polynomial `A(x) = a_0 +
+/// a_1*x` are enough to interpolate the line and read off `A(0) = a_0`.
+/// Method: recover_secret
+/// Parameters: share1 - (x, y) of the first share, share2 - (x, y) of the second share
+/// Response: Some(identity_secret) if the shares come from two distinct signals
+///           (`x1 != x2`), None if the x-coordinates collide (no new information)
+pub fn recover_secret(share1: (Fr, Fr), share2: (Fr, Fr)) -> Option<Fr> {
+    let (x1, y1) = share1;
+    let (x2, y2) = share2;
+    if x1 == x2 {
+        return None;
+    }
+
+    let slope = (y2 - y1) * (x2 - x1).inverse()?;
+    Some(y1 - slope * x1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::rand::rngs::StdRng;
+    use ark_std::rand::SeedableRng;
+
+    #[test]
+    fn test_rln_single_signal_is_accepted_and_private() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut acc = Bn254Accumulator::setup(8);
+        let identity_secret = Bn254Accumulator::hash_to_scalar(b"alice's identity secret");
+        acc.add_member_scalar(identity_commitment(identity_secret)).unwrap();
+
+        let (pk, vk) = setup_rln_circuit(&mut rng);
+        let epoch = Bn254Accumulator::hash_to_scalar(b"epoch-1");
+        let proof = rln_prove(&acc, identity_secret, epoch, b"hello", &pk, &mut rng).unwrap();
+
+        assert!(rln_verify(&vk, &acc, b"hello", &proof));
+        // A single share reveals nothing about identity_secret on its own: recovery
+        // needs a second, distinct point on the same line.
+        assert!(recover_secret((proof.share_x, proof.share_y), (proof.share_x, proof.share_y)).is_none());
+    }
+
+    #[test]
+    fn test_rln_rejects_forged_share_y() {
+        // The old cleartext check never looked at share_y, so a forged share_y alongside
+        // an otherwise-legitimate witness used to be accepted. It now must fail, since the
+        // circuit itself re-derives share_y from the identity secret.
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut acc = Bn254Accumulator::setup(8);
+        let identity_secret = Bn254Accumulator::hash_to_scalar(b"alice's identity secret");
+        acc.add_member_scalar(identity_commitment(identity_secret)).unwrap();
+
+        let (pk, vk) = setup_rln_circuit(&mut rng);
+        let epoch = Bn254Accumulator::hash_to_scalar(b"epoch-1");
+        let mut proof = rln_prove(&acc, identity_secret, epoch, b"hello", &pk, &mut rng).unwrap();
+        proof.share_y += Fr::from(1u64);
+
+        assert!(!rln_verify(&vk, &acc, b"hello", &proof));
+    }
+
+    #[test]
+    fn test_rln_double_signal_recovers_secret() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut acc = Bn254Accumulator::setup(8);
+        let identity_secret = Bn254Accumulator::hash_to_scalar(b"mallory's identity secret");
+        acc.add_member_scalar(identity_commitment(identity_secret)).unwrap();
+
+        let (pk, _vk) = setup_rln_circuit(&mut rng);
+        let epoch = Bn254Accumulator::hash_to_scalar(b"epoch-1");
+        let proof1 = rln_prove(&acc, identity_secret, epoch, b"signal one", &pk, &mut rng).unwrap();
+        let proof2 = rln_prove(&acc, identity_secret, epoch, b"signal two", &pk, &mut rng).unwrap();
+
+        assert_eq!(proof1.nullifier, proof2.nullifier, "same epoch should yield the same nullifier");
+        assert_ne!(proof1.share_x, proof2.share_x, "distinct signals should yield distinct share x-coordinates");
+
+        let recovered = recover_secret(
+            (proof1.share_x, proof1.share_y),
+            (proof2.share_x, proof2.share_y),
+        )
+        .unwrap();
+        assert_eq!(recovered, identity_secret);
+    }
+}
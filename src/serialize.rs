@@ -0,0 +1,133 @@
+//! Fixed-width, big-endian wire format for the crate's field elements and curve points.
+//!
+//! This intentionally does not reuse `ark_serialize`'s `CanonicalSerialize` (which is
+//! little-endian and treated as an arkworks-internal implementation detail, not a stable
+//! cross-language spec): anything crossing the FFI boundary - accumulator state,
+//! witnesses, proofs - needs an explicit, documented encoding instead.
+
+use ark_bn254::{Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+
+pub const FR_BYTES: usize = 32;
+pub const FQ_BYTES: usize = 32;
+pub const G1_BYTES: usize = 2 * FQ_BYTES;
+pub const G2_BYTES: usize = 4 * FQ_BYTES;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    UnexpectedLength,
+    InvalidPoint,
+}
+
+fn write_be(out: &mut [u8], bytes: Vec<u8>) {
+    out[out.len() - bytes.len()..].copy_from_slice(&bytes);
+}
+
+pub fn fr_to_be_bytes(x: Fr) -> [u8; FR_BYTES] {
+    let mut out = [0u8; FR_BYTES];
+    write_be(&mut out, x.into_bigint().to_bytes_be());
+    out
+}
+
+pub fn fr_from_be_bytes(bytes: &[u8]) -> Result<Fr, DecodeError> {
+    if bytes.len() != FR_BYTES {
+        return Err(DecodeError::UnexpectedLength);
+    }
+    Ok(Fr::from_be_bytes_mod_order(bytes))
+}
+
+fn fq_to_be_bytes(x: Fq) -> [u8; FQ_BYTES] {
+    let mut out = [0u8; FQ_BYTES];
+    write_be(&mut out, x.into_bigint().to_bytes_be());
+    out
+}
+
+fn fq_from_be_bytes(bytes: &[u8]) -> Fq {
+    Fq::from_be_bytes_mod_order(bytes)
+}
+
+/// Encodes a G1 point as big-endian `(x, y)`. The accumulator never holds the point at
+/// infinity (the empty-product accumulator value is `g1`, not infinity), so that case is
+/// intentionally not represented.
+pub fn g1_to_be_bytes(p: G1Projective) -> [u8; G1_BYTES] {
+    let affine = p.into_affine();
+    let mut out = [0u8; G1_BYTES];
+    out[..FQ_BYTES].copy_from_slice(&fq_to_be_bytes(affine.x));
+    out[FQ_BYTES..].copy_from_slice(&fq_to_be_bytes(affine.y));
+    out
+}
+
+pub fn g1_from_be_bytes(bytes: &[u8]) -> Result<G1Projective, DecodeError> {
+    if bytes.len() != G1_BYTES {
+        return Err(DecodeError::UnexpectedLength);
+    }
+    let x = fq_from_be_bytes(&bytes[..FQ_BYTES]);
+    let y = fq_from_be_bytes(&bytes[FQ_BYTES..]);
+    let affine = G1Affine::new_unchecked(x, y);
+    if !affine.is_on_curve() || !affine.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(DecodeError::InvalidPoint);
+    }
+    Ok(affine.into())
+}
+
+pub fn g2_to_be_bytes(p: G2Projective) -> [u8; G2_BYTES] {
+    let affine = p.into_affine();
+    let mut out = [0u8; G2_BYTES];
+    out[..FQ_BYTES].copy_from_slice(&fq_to_be_bytes(affine.x.c0));
+    out[FQ_BYTES..2 * FQ_BYTES].copy_from_slice(&fq_to_be_bytes(affine.x.c1));
+    out[2 * FQ_BYTES..3 * FQ_BYTES].copy_from_slice(&fq_to_be_bytes(affine.y.c0));
+    out[3 * FQ_BYTES..].copy_from_slice(&fq_to_be_bytes(affine.y.c1));
+    out
+}
+
+pub fn g2_from_be_bytes(bytes: &[u8]) -> Result<G2Projective, DecodeError> {
+    if bytes.len() != G2_BYTES {
+        return Err(DecodeError::UnexpectedLength);
+    }
+    let x = Fq2::new(
+        fq_from_be_bytes(&bytes[..FQ_BYTES]),
+        fq_from_be_bytes(&bytes[FQ_BYTES..2 * FQ_BYTES]),
+    );
+    let y = Fq2::new(
+        fq_from_be_bytes(&bytes[2 * FQ_BYTES..3 * FQ_BYTES]),
+        fq_from_be_bytes(&bytes[3 * FQ_BYTES..]),
+    );
+    let affine = G2Affine::new_unchecked(x, y);
+    if !affine.is_on_curve() || !affine.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(DecodeError::InvalidPoint);
+    }
+    Ok(affine.into())
+}
+
+/// A small cursor over a byte slice, used to decode the length-prefixed fields in
+/// `Bn254Accumulator::from_bytes` without tracking an offset by hand at every call site.
+pub struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    pub fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self.pos.checked_add(n).ok_or(DecodeError::UnexpectedLength)?;
+        let slice = self.buf.get(self.pos..end).ok_or(DecodeError::UnexpectedLength)?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    pub fn take_u64(&mut self) -> Result<u64, DecodeError> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_be_bytes(bytes.try_into().expect("slice of length 8")))
+    }
+
+    /// The number of bytes not yet consumed. Used to bound-check attacker-controlled
+    /// length prefixes (e.g. `powers_len`/`members_len` in `Bn254Accumulator::from_bytes`)
+    /// against what's actually left in the buffer before allocating a `Vec` sized by them.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+}